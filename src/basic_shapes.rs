@@ -4,12 +4,12 @@ use crate::{
     conversions::{ToLyonPoint, ToLyonVector},
     create_sprite,
     shape_plugin::ShapeDescriptor,
-    Geometry, ShapeSprite, TessellationMode, Tessellator,
+    Geometry, ShapeSprite, ShapeVertex, TessellationMode, Tessellator,
 };
 use bevy::prelude::*;
 use lyon_tessellation::{
-    math::{Angle, Point, Rect, Size},
-    path::{Polygon, Winding},
+    math::{point, vector, Angle, Point, Rect, Size},
+    path::{path::Builder, Path, Polygon, Winding},
     BuffersBuilder, FillVertex, StrokeVertex, VertexBuffers,
 };
 
@@ -29,11 +29,36 @@ impl Default for RectangleOrigin {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// The translation to pass to `create_sprite`: each tessellated vertex
+/// already bakes `transform.translation.z()` into its own position, so the
+/// sprite's own `Transform` must be zeroed on that axis or the depth gets
+/// counted twice.
+fn sprite_translation(transform: &Transform) -> Vec3 {
+    Vec3::new(transform.translation.x(), transform.translation.y(), 0.0)
+}
+
+#[cfg(test)]
+mod sprite_translation_tests {
+    use super::*;
+
+    #[test]
+    fn zeroes_z_since_vertices_already_bake_it() {
+        let transform = Transform::from_translation(Vec3::new(1.0, 2.0, 3.0));
+
+        let translation = sprite_translation(&transform);
+
+        assert_eq!(translation, Vec3::new(1.0, 2.0, 0.0));
+    }
+}
+
+/// Not `Copy`, unlike before `gradient` was added: `Gradient`'s stops are
+/// heap-allocated, the same reason [`PolygonShape`] isn't `Copy` either.
+#[derive(Debug, Clone, PartialEq)]
 pub struct RectangleShape {
     pub width: f32,
     pub height: f32,
     pub origin: RectangleOrigin,
+    pub gradient: Option<Gradient>,
 }
 
 impl Default for RectangleShape {
@@ -42,6 +67,7 @@ impl Default for RectangleShape {
             width: 1.0,
             height: 1.0,
             origin: RectangleOrigin::default(),
+            gradient: None,
         }
     }
 }
@@ -69,7 +95,13 @@ impl ShapeSprite for RectangleShape {
         match mode {
             TessellationMode::Fill(options) => {
                 let ref mut output = BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
-                    [vertex.position().x, vertex.position().y, 0.0]
+                    let p = vertex.position();
+                    let color =
+                        vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), Color::WHITE);
+                    ShapeVertex {
+                        position: [p.x, p.y, transform.translation.z()],
+                        color: [color.r, color.g, color.b, color.a],
+                    }
                 });
                 tessellator
                     .fill
@@ -85,7 +117,61 @@ impl ShapeSprite for RectangleShape {
             TessellationMode::Stroke(options) => {
                 let ref mut output =
                     BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
-                        [vertex.position().x, vertex.position().y, 0.0]
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), Color::WHITE);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_rectangle(
+                        &Rect::new(origin, Size::new(self.width, self.height)),
+                        &options,
+                        output,
+                    )
+                    .unwrap();
+            }
+            TessellationMode::FillAndStroke {
+                fill,
+                stroke,
+                fill_color,
+                stroke_color,
+            } => {
+                let ref mut fill_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), fill_color);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_rectangle(
+                        &Rect::new(origin, Size::new(self.width, self.height)),
+                        &fill,
+                        fill_output,
+                    )
+                    .unwrap();
+
+                let ref mut stroke_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), stroke_color);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
                     });
                 tessellator
                     .stroke
@@ -93,24 +179,520 @@ impl ShapeSprite for RectangleShape {
                     .unwrap()
                     .tessellate_rectangle(
                         &Rect::new(origin, Size::new(self.width, self.height)),
+                        &stroke,
+                        stroke_output,
+                    )
+                    .unwrap();
+            }
+        }
+
+        create_sprite(material, meshes, geometry, sprite_translation(&transform))
+    }
+}
+
+/// Radius of each corner of a [`RoundedRectangleShape`], in local units.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct RectangleCorners {
+    pub top_left: f32,
+    pub top_right: f32,
+    pub bottom_left: f32,
+    pub bottom_right: f32,
+}
+
+impl RectangleCorners {
+    /// Same radius on all four corners.
+    pub fn all(radius: f32) -> Self {
+        Self {
+            top_left: radius,
+            top_right: radius,
+            bottom_left: radius,
+            bottom_right: radius,
+        }
+    }
+}
+
+/// Like [`RectangleShape`], but with optionally rounded corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundedRectangleShape {
+    pub width: f32,
+    pub height: f32,
+    pub origin: RectangleOrigin,
+    pub corners: RectangleCorners,
+}
+
+impl Default for RoundedRectangleShape {
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            height: 1.0,
+            origin: RectangleOrigin::default(),
+            corners: RectangleCorners::default(),
+        }
+    }
+}
+
+impl RoundedRectangleShape {
+    /// Clamps each corner radius to at most half the shorter side, so
+    /// opposite corners never overlap.
+    fn clamped_corners(&self) -> RectangleCorners {
+        let max_radius = self.width.min(self.height) / 2.0;
+        let clamp = |r: f32| r.max(0.0).min(max_radius);
+        RectangleCorners {
+            top_left: clamp(self.corners.top_left),
+            top_right: clamp(self.corners.top_right),
+            bottom_left: clamp(self.corners.bottom_left),
+            bottom_right: clamp(self.corners.bottom_right),
+        }
+    }
+
+    /// Builds the outline as a lyon [`Path`], clamping each radius to at
+    /// most half the shorter side so opposite corners never overlap.
+    fn path(&self) -> Path {
+        use RectangleOrigin::*;
+        let origin = match self.origin {
+            Center => Point::new(-self.width / 2.0, -self.height / 2.0),
+            BottomLeft => Point::new(0.0, 0.0),
+            BottomRight => Point::new(-self.width, 0.0),
+            TopRight => Point::new(-self.width, -self.height),
+            TopLeft => Point::new(0.0, -self.height),
+        };
+
+        let RectangleCorners {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+        } = self.clamped_corners();
+
+        let left = origin.x;
+        let bottom = origin.y;
+        let right = origin.x + self.width;
+        let top = origin.y + self.height;
+
+        let mut builder = Builder::new();
+        builder.move_to(point(left + bottom_left, bottom));
+
+        builder.line_to(point(right - bottom_right, bottom));
+        if bottom_right > 0.0 {
+            builder.arc(
+                point(right - bottom_right, bottom + bottom_right),
+                vector(bottom_right, bottom_right),
+                Angle::frac_pi_2(),
+                Angle::zero(),
+            );
+        }
+
+        builder.line_to(point(right, top - top_right));
+        if top_right > 0.0 {
+            builder.arc(
+                point(right - top_right, top - top_right),
+                vector(top_right, top_right),
+                Angle::frac_pi_2(),
+                Angle::zero(),
+            );
+        }
+
+        builder.line_to(point(left + top_left, top));
+        if top_left > 0.0 {
+            builder.arc(
+                point(left + top_left, top - top_left),
+                vector(top_left, top_left),
+                Angle::frac_pi_2(),
+                Angle::zero(),
+            );
+        }
+
+        builder.line_to(point(left, bottom + bottom_left));
+        if bottom_left > 0.0 {
+            builder.arc(
+                point(left + bottom_left, bottom + bottom_left),
+                vector(bottom_left, bottom_left),
+                Angle::frac_pi_2(),
+                Angle::zero(),
+            );
+        }
+
+        builder.close();
+        builder.build()
+    }
+}
+
+#[cfg(test)]
+mod rounded_rectangle_tests {
+    use super::*;
+
+    #[test]
+    fn corners_are_kept_as_is_when_they_fit() {
+        let shape = RoundedRectangleShape {
+            width: 10.0,
+            height: 20.0,
+            corners: RectangleCorners::all(4.0),
+            ..RoundedRectangleShape::default()
+        };
+
+        let corners = shape.clamped_corners();
+
+        assert_eq!(corners, RectangleCorners::all(4.0));
+    }
+
+    #[test]
+    fn corners_are_clamped_to_half_the_shorter_side() {
+        let shape = RoundedRectangleShape {
+            width: 10.0,
+            height: 20.0,
+            corners: RectangleCorners::all(100.0),
+            ..RoundedRectangleShape::default()
+        };
+
+        let corners = shape.clamped_corners();
+
+        assert_eq!(corners, RectangleCorners::all(5.0));
+    }
+
+    #[test]
+    fn negative_radii_are_clamped_to_zero() {
+        let shape = RoundedRectangleShape {
+            width: 10.0,
+            height: 10.0,
+            corners: RectangleCorners::all(-1.0),
+            ..RoundedRectangleShape::default()
+        };
+
+        let corners = shape.clamped_corners();
+
+        assert_eq!(corners, RectangleCorners::all(0.0));
+    }
+}
+
+impl ShapeSprite for RoundedRectangleShape {
+    fn generate_sprite(
+        &self,
+        material: Handle<ColorMaterial>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        tessellator: &mut Tessellator,
+        mode: TessellationMode,
+        transform: Transform,
+    ) -> SpriteBundle {
+        let mut geometry = Geometry(VertexBuffers::new());
+        let path = self.path();
+
+        match mode {
+            TessellationMode::Fill(options) => {
+                let ref mut output = BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                    let p = vertex.position();
+                    let color = Color::WHITE;
+                    ShapeVertex {
+                        position: [p.x, p.y, transform.translation.z()],
+                        color: [color.r, color.g, color.b, color.a],
+                    }
+                });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_path(
+                        &path,
+                        &options,
+                        output,
+                    )
+                    .unwrap();
+            }
+            TessellationMode::Stroke(options) => {
+                let ref mut output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color = Color::WHITE;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_path(
+                        &path,
                         &options,
                         output,
                     )
                     .unwrap();
             }
+            TessellationMode::FillAndStroke {
+                fill,
+                stroke,
+                fill_color,
+                stroke_color,
+            } => {
+                let ref mut fill_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                        let p = vertex.position();
+                        let color = fill_color;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_path(
+                        &path,
+                        &fill,
+                        fill_output,
+                    )
+                    .unwrap();
+
+                let ref mut stroke_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color = stroke_color;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_path(
+                        &path,
+                        &stroke,
+                        stroke_output,
+                    )
+                    .unwrap();
+            }
         }
 
-        create_sprite(material, meshes, geometry, transform.translation)
+        create_sprite(material, meshes, geometry, sprite_translation(&transform))
     }
 }
 
+/// A single color stop along a [`Gradient`], at position `0.0..=1.0`.
 #[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GradientStop {
+    pub position: f32,
+    pub color: Color,
+}
+
+/// A linear or radial color gradient, sampled per-vertex across a shape.
+///
+/// `stops` must be sorted by [`GradientStop::position`]; positions outside
+/// `[0.0, 1.0]` are clamped to the nearest end stop.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Gradient {
+    Linear {
+        start: Vec2,
+        end: Vec2,
+        stops: Vec<GradientStop>,
+    },
+    Radial {
+        center: Vec2,
+        radius: f32,
+        stops: Vec<GradientStop>,
+    },
+}
+
+impl Gradient {
+    /// Projects `position` onto the gradient axis and interpolates the
+    /// surrounding stops. Called once per vertex in each gradient-capable
+    /// shape's `generate_sprite`, feeding the result into the mesh's
+    /// `ATTRIBUTE_COLOR`.
+    ///
+    /// `stops` must be sorted by [`GradientStop::position`]; debug builds
+    /// assert this since `Linear`/`Radial`'s fields are public and nothing
+    /// else enforces it at construction time.
+    pub fn sample(&self, position: Vec2) -> Color {
+        let (t, stops) = match self {
+            Gradient::Linear { start, end, stops } => {
+                let axis = *end - *start;
+                let len_sq = axis.dot(axis);
+                let t = if len_sq > 0.0 {
+                    (position - *start).dot(axis) / len_sq
+                } else {
+                    0.0
+                };
+                (t, stops)
+            }
+            Gradient::Radial {
+                center,
+                radius,
+                stops,
+            } => {
+                let t = if *radius > 0.0 {
+                    (position - *center).length() / radius
+                } else {
+                    0.0
+                };
+                (t, stops)
+            }
+        };
+
+        debug_assert!(
+            stops.windows(2).all(|w| w[0].position <= w[1].position),
+            "Gradient stops must be sorted by position, got {:?}",
+            stops
+        );
+
+        Self::sample_stops(stops, t.max(0.0).min(1.0))
+    }
+
+    fn sample_stops(stops: &[GradientStop], t: f32) -> Color {
+        if stops.is_empty() {
+            return Color::WHITE;
+        }
+        if stops.len() == 1 || t <= stops[0].position {
+            return stops[0].color;
+        }
+        if t >= stops[stops.len() - 1].position {
+            return stops[stops.len() - 1].color;
+        }
+
+        // Binary search for the first stop whose position is >= t.
+        let idx = match stops.binary_search_by(|stop| {
+            stop.position.partial_cmp(&t).unwrap_or(std::cmp::Ordering::Equal)
+        }) {
+            Ok(idx) => return stops[idx].color,
+            Err(idx) => idx,
+        };
+
+        let lower = &stops[idx - 1];
+        let upper = &stops[idx];
+        let span = upper.position - lower.position;
+        let local_t = if span > 0.0 {
+            (t - lower.position) / span
+        } else {
+            0.0
+        };
+
+        Color::rgba(
+            lower.color.r + (upper.color.r - lower.color.r) * local_t,
+            lower.color.g + (upper.color.g - lower.color.g) * local_t,
+            lower.color.b + (upper.color.b - lower.color.b) * local_t,
+            lower.color.a + (upper.color.a - lower.color.a) * local_t,
+        )
+    }
+}
+
+#[cfg(test)]
+mod gradient_tests {
+    use super::*;
+
+    fn stops() -> Vec<GradientStop> {
+        vec![
+            GradientStop {
+                position: 0.0,
+                color: Color::rgba(0.0, 0.0, 0.0, 1.0),
+            },
+            GradientStop {
+                position: 1.0,
+                color: Color::rgba(1.0, 1.0, 1.0, 1.0),
+            },
+        ]
+    }
+
+    #[test]
+    fn linear_gradient_interpolates_between_stops() {
+        let gradient = Gradient::Linear {
+            start: Vec2::new(0.0, 0.0),
+            end: Vec2::new(10.0, 0.0),
+            stops: stops(),
+        };
+
+        let color = gradient.sample(Vec2::new(5.0, 0.0));
+
+        assert_eq!(color, Color::rgba(0.5, 0.5, 0.5, 1.0));
+    }
+
+    #[test]
+    fn linear_gradient_clamps_past_the_end_stops() {
+        let gradient = Gradient::Linear {
+            start: Vec2::new(0.0, 0.0),
+            end: Vec2::new(10.0, 0.0),
+            stops: stops(),
+        };
+
+        assert_eq!(
+            gradient.sample(Vec2::new(-5.0, 0.0)),
+            Color::rgba(0.0, 0.0, 0.0, 1.0)
+        );
+        assert_eq!(
+            gradient.sample(Vec2::new(15.0, 0.0)),
+            Color::rgba(1.0, 1.0, 1.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn radial_gradient_interpolates_by_distance_from_center() {
+        let gradient = Gradient::Radial {
+            center: Vec2::new(0.0, 0.0),
+            radius: 10.0,
+            stops: stops(),
+        };
+
+        let color = gradient.sample(Vec2::new(0.0, 5.0));
+
+        assert_eq!(color, Color::rgba(0.5, 0.5, 0.5, 1.0));
+    }
+}
+
+/// Picks a vertex's color: the gradient sample if one is set, otherwise
+/// `fallback` (a shape's fill or stroke color).
+fn vertex_color(gradient: Option<&Gradient>, position: Vec2, fallback: Color) -> Color {
+    gradient.map(|g| g.sample(position)).unwrap_or(fallback)
+}
+
+#[cfg(test)]
+mod vertex_color_tests {
+    use super::*;
+
+    #[test]
+    fn fill_and_stroke_get_their_own_fallback_color_without_a_gradient() {
+        let fill_color = Color::rgba(1.0, 0.0, 0.0, 1.0);
+        let stroke_color = Color::rgba(0.0, 0.0, 1.0, 1.0);
+
+        let fill = vertex_color(None, Vec2::zero(), fill_color);
+        let stroke = vertex_color(None, Vec2::zero(), stroke_color);
+
+        assert_eq!(fill, fill_color);
+        assert_eq!(stroke, stroke_color);
+        assert_ne!(fill, stroke);
+    }
+
+    #[test]
+    fn gradient_overrides_the_fallback_when_set() {
+        let gradient = Gradient::Linear {
+            start: Vec2::new(0.0, 0.0),
+            end: Vec2::new(10.0, 0.0),
+            stops: vec![
+                GradientStop {
+                    position: 0.0,
+                    color: Color::rgba(0.0, 0.0, 0.0, 1.0),
+                },
+                GradientStop {
+                    position: 1.0,
+                    color: Color::rgba(1.0, 1.0, 1.0, 1.0),
+                },
+            ],
+        };
+
+        let color = vertex_color(Some(&gradient), Vec2::new(10.0, 0.0), Color::WHITE);
+
+        assert_eq!(color, Color::rgba(1.0, 1.0, 1.0, 1.0));
+    }
+}
+
+/// Not `Copy` because of `gradient`; see [`RectangleShape`].
+#[derive(Debug, Clone, PartialEq)]
 pub struct CircleShape {
     /// Distance of the border of the circle from the center.
     pub radius: f32,
     /// The position of the center of the circle, relative to the world
     /// [`Translation`] of the [`SpriteBundle`].
     pub center: Vec2,
+    pub gradient: Option<Gradient>,
 }
 
 impl CircleShape {
@@ -136,6 +718,7 @@ impl Default for CircleShape {
         Self {
             radius: 1.0,
             center: Vec2::zero(),
+            gradient: None,
         }
     }
 }
@@ -154,39 +737,113 @@ impl ShapeSprite for CircleShape {
         match mode {
             TessellationMode::Fill(options) => {
                 let ref mut output = BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
-                    [vertex.position().x, vertex.position().y, 0.0]
+                    let p = vertex.position();
+                    let color =
+                        vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), Color::WHITE);
+                    ShapeVertex {
+                        position: [p.x, p.y, transform.translation.z()],
+                        color: [color.r, color.g, color.b, color.a],
+                    }
                 });
                 tessellator
                     .fill
                     .as_mut()
                     .unwrap()
-                    .tessellate_circle(self.center.to_lyon_point(), self.radius, &options, output)
+                    .tessellate_circle(
+                        self.center.to_lyon_point(),
+                        self.radius,
+                        &options,
+                        output,
+                    )
                     .unwrap();
             }
             TessellationMode::Stroke(options) => {
                 let ref mut output =
                     BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
-                        [vertex.position().x, vertex.position().y, 0.0]
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), Color::WHITE);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_circle(
+                        self.center.to_lyon_point(),
+                        self.radius,
+                        &options,
+                        output,
+                    )
+                    .unwrap();
+            }
+            TessellationMode::FillAndStroke {
+                fill,
+                stroke,
+                fill_color,
+                stroke_color,
+            } => {
+                let ref mut fill_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), fill_color);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_circle(
+                        self.center.to_lyon_point(),
+                        self.radius,
+                        &fill,
+                        fill_output,
+                    )
+                    .unwrap();
+
+                let ref mut stroke_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), stroke_color);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
                     });
                 tessellator
                     .stroke
                     .as_mut()
                     .unwrap()
-                    .tessellate_circle(self.center.to_lyon_point(), self.radius, &options, output)
+                    .tessellate_circle(
+                        self.center.to_lyon_point(),
+                        self.radius,
+                        &stroke,
+                        stroke_output,
+                    )
                     .unwrap();
             }
         }
 
-        create_sprite(material, meshes, geometry, transform.translation)
+        create_sprite(material, meshes, geometry, sprite_translation(&transform))
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Not `Copy` because of `gradient`; see [`RectangleShape`].
+#[derive(Debug, Clone, PartialEq)]
 pub struct EllipseShape {
     pub radii: Vec2,
     /// The position of the center of the ellipse, relative to the world
     /// [`Translation`] of the [`SpriteBundle`].
     pub center: Vec2,
+    pub gradient: Option<Gradient>,
 }
 
 impl Default for EllipseShape {
@@ -194,6 +851,7 @@ impl Default for EllipseShape {
         Self {
             radii: Vec2::one(),
             center: Vec2::zero(),
+            gradient: None,
         }
     }
 }
@@ -212,7 +870,13 @@ impl ShapeSprite for EllipseShape {
         match mode {
             TessellationMode::Fill(options) => {
                 let ref mut output = BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
-                    [vertex.position().x, vertex.position().y, 0.0]
+                    let p = vertex.position();
+                    let color =
+                        vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), Color::WHITE);
+                    ShapeVertex {
+                        position: [p.x, p.y, transform.translation.z()],
+                        color: [color.r, color.g, color.b, color.a],
+                    }
                 });
                 tessellator
                     .fill
@@ -231,7 +895,13 @@ impl ShapeSprite for EllipseShape {
             TessellationMode::Stroke(options) => {
                 let ref mut output =
                     BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
-                        [vertex.position().x, vertex.position().y, 0.0]
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), Color::WHITE);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
                     });
                 tessellator
                     .stroke
@@ -247,23 +917,79 @@ impl ShapeSprite for EllipseShape {
                     )
                     .unwrap();
             }
-        }
-
-        create_sprite(material, meshes, geometry, transform.translation)
-    }
-}
-
-#[derive(Debug, PartialEq)]
-pub struct PolygonShape {
-    pub points: Vec<Vec2>,
-    pub closed: bool,
-}
+            TessellationMode::FillAndStroke {
+                fill,
+                stroke,
+                fill_color,
+                stroke_color,
+            } => {
+                let ref mut fill_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), fill_color);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_ellipse(
+                        self.center.to_lyon_point(),
+                        self.radii.to_lyon_vector(),
+                        Angle::zero(),
+                        Winding::Positive,
+                        &fill,
+                        fill_output,
+                    )
+                    .unwrap();
+
+                let ref mut stroke_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), stroke_color);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_ellipse(
+                        self.center.to_lyon_point(),
+                        self.radii.to_lyon_vector(),
+                        Angle::zero(),
+                        Winding::Positive,
+                        &stroke,
+                        stroke_output,
+                    )
+                    .unwrap();
+            }
+        }
+
+        create_sprite(material, meshes, geometry, sprite_translation(&transform))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PolygonShape {
+    pub points: Vec<Vec2>,
+    pub closed: bool,
+    pub gradient: Option<Gradient>,
+}
 
 impl Default for PolygonShape {
     fn default() -> Self {
         Self {
             points: Vec::new(),
             closed: true,
+            gradient: None,
         }
     }
 }
@@ -292,29 +1018,787 @@ impl ShapeSprite for PolygonShape {
         match mode {
             TessellationMode::Fill(options) => {
                 let ref mut output = BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
-                    [vertex.position().x, vertex.position().y, 0.0]
+                    let p = vertex.position();
+                    let color =
+                        vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), Color::WHITE);
+                    ShapeVertex {
+                        position: [p.x, p.y, transform.translation.z()],
+                        color: [color.r, color.g, color.b, color.a],
+                    }
+                });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_polygon(
+                        polygon,
+                        &options,
+                        output,
+                    )
+                    .unwrap();
+            }
+            TessellationMode::Stroke(options) => {
+                let ref mut output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), Color::WHITE);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_polygon(
+                        polygon,
+                        &options,
+                        output,
+                    )
+                    .unwrap();
+            }
+            TessellationMode::FillAndStroke {
+                fill,
+                stroke,
+                fill_color,
+                stroke_color,
+            } => {
+                let ref mut fill_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), fill_color);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_polygon(
+                        polygon,
+                        &fill,
+                        fill_output,
+                    )
+                    .unwrap();
+
+                let ref mut stroke_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color =
+                            vertex_color(self.gradient.as_ref(), Vec2::new(p.x, p.y), stroke_color);
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_polygon(
+                        polygon,
+                        &stroke,
+                        stroke_output,
+                    )
+                    .unwrap();
+            }
+        }
+
+        create_sprite(material, meshes, geometry, sprite_translation(&transform))
+    }
+}
+
+/// A polygon with `sides` vertices evenly spaced around `center`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegularPolygonShape {
+    pub sides: usize,
+    pub radius: f32,
+    pub center: Vec2,
+    /// Angle, in radians, of the first vertex.
+    pub start_angle: f32,
+}
+
+impl Default for RegularPolygonShape {
+    fn default() -> Self {
+        Self {
+            sides: 3,
+            radius: 1.0,
+            center: Vec2::zero(),
+            start_angle: 0.0,
+        }
+    }
+}
+
+impl RegularPolygonShape {
+    fn points(&self) -> Vec<Vec2> {
+        (0..self.sides)
+            .map(|i| {
+                let angle = self.start_angle
+                    + 2.0 * std::f32::consts::PI * i as f32 / self.sides as f32;
+                self.center + Vec2::new(angle.cos(), angle.sin()) * self.radius
+            })
+            .collect()
+    }
+}
+
+impl ShapeSprite for RegularPolygonShape {
+    fn generate_sprite(
+        &self,
+        material: Handle<ColorMaterial>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        tessellator: &mut Tessellator,
+        mode: TessellationMode,
+        transform: Transform,
+    ) -> SpriteBundle {
+        let mut geometry = Geometry(VertexBuffers::new());
+
+        let points = self
+            .points()
+            .iter()
+            .map(|p| p.to_lyon_point())
+            .collect::<Vec<Point>>();
+        let polygon: Polygon<Point> = Polygon {
+            points: points.as_slice(),
+            closed: true,
+        };
+
+        match mode {
+            TessellationMode::Fill(options) => {
+                let ref mut output = BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                    let p = vertex.position();
+                    let color = Color::WHITE;
+                    ShapeVertex {
+                        position: [p.x, p.y, transform.translation.z()],
+                        color: [color.r, color.g, color.b, color.a],
+                    }
                 });
                 tessellator
                     .fill
                     .as_mut()
                     .unwrap()
-                    .tessellate_polygon(polygon, &options, output)
+                    .tessellate_polygon(
+                        polygon,
+                        &options,
+                        output,
+                    )
                     .unwrap();
             }
             TessellationMode::Stroke(options) => {
                 let ref mut output =
                     BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
-                        [vertex.position().x, vertex.position().y, 0.0]
+                        let p = vertex.position();
+                        let color = Color::WHITE;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_polygon(
+                        polygon,
+                        &options,
+                        output,
+                    )
+                    .unwrap();
+            }
+            TessellationMode::FillAndStroke {
+                fill,
+                stroke,
+                fill_color,
+                stroke_color,
+            } => {
+                let ref mut fill_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                        let p = vertex.position();
+                        let color = fill_color;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_polygon(
+                        polygon,
+                        &fill,
+                        fill_output,
+                    )
+                    .unwrap();
+
+                let ref mut stroke_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color = stroke_color;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
                     });
                 tessellator
                     .stroke
                     .as_mut()
                     .unwrap()
-                    .tessellate_polygon(polygon, &options, output)
+                    .tessellate_polygon(
+                        polygon,
+                        &stroke,
+                        stroke_output,
+                    )
+                    .unwrap();
+            }
+        }
+
+        create_sprite(material, meshes, geometry, sprite_translation(&transform))
+    }
+}
+
+/// A star polygon with `points` spikes, alternating between `outer_radius`
+/// and `inner_radius` as the angle advances around `center`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StarShape {
+    pub points: usize,
+    pub inner_radius: f32,
+    pub outer_radius: f32,
+    pub center: Vec2,
+}
+
+impl Default for StarShape {
+    fn default() -> Self {
+        Self {
+            points: 5,
+            inner_radius: 0.5,
+            outer_radius: 1.0,
+            center: Vec2::zero(),
+        }
+    }
+}
+
+impl StarShape {
+    fn points(&self) -> Vec<Vec2> {
+        (0..self.points * 2)
+            .map(|i| {
+                let angle = std::f32::consts::PI * i as f32 / self.points as f32;
+                let radius = if i % 2 == 0 {
+                    self.outer_radius
+                } else {
+                    self.inner_radius
+                };
+                self.center + Vec2::new(angle.cos(), angle.sin()) * radius
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod polygon_generator_tests {
+    use super::*;
+
+    fn assert_close(a: Vec2, b: Vec2) {
+        assert!(
+            (a - b).length() < 1e-5,
+            "expected {:?} to be close to {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn regular_polygon_places_vertices_evenly_around_the_center() {
+        let shape = RegularPolygonShape {
+            sides: 4,
+            radius: 2.0,
+            center: Vec2::zero(),
+            start_angle: 0.0,
+        };
+
+        let points = shape.points();
+
+        assert_eq!(points.len(), 4);
+        assert_close(points[0], Vec2::new(2.0, 0.0));
+        assert_close(points[1], Vec2::new(0.0, 2.0));
+        assert_close(points[2], Vec2::new(-2.0, 0.0));
+        assert_close(points[3], Vec2::new(0.0, -2.0));
+    }
+
+    #[test]
+    fn regular_polygon_is_offset_by_start_angle_and_center() {
+        let shape = RegularPolygonShape {
+            sides: 4,
+            radius: 1.0,
+            center: Vec2::new(5.0, 5.0),
+            start_angle: std::f32::consts::FRAC_PI_2,
+        };
+
+        let points = shape.points();
+
+        assert_close(points[0], Vec2::new(5.0, 6.0));
+    }
+
+    #[test]
+    fn star_alternates_outer_and_inner_radius() {
+        let shape = StarShape {
+            points: 5,
+            inner_radius: 1.0,
+            outer_radius: 2.0,
+            center: Vec2::zero(),
+        };
+
+        let points = shape.points();
+
+        assert_eq!(points.len(), 10);
+        for (i, p) in points.iter().enumerate() {
+            let expected_radius = if i % 2 == 0 { 2.0 } else { 1.0 };
+            assert!(
+                (p.length() - expected_radius).abs() < 1e-5,
+                "point {} had radius {}, expected {}",
+                i,
+                p.length(),
+                expected_radius
+            );
+        }
+    }
+}
+
+impl ShapeSprite for StarShape {
+    fn generate_sprite(
+        &self,
+        material: Handle<ColorMaterial>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        tessellator: &mut Tessellator,
+        mode: TessellationMode,
+        transform: Transform,
+    ) -> SpriteBundle {
+        let mut geometry = Geometry(VertexBuffers::new());
+
+        let points = self
+            .points()
+            .iter()
+            .map(|p| p.to_lyon_point())
+            .collect::<Vec<Point>>();
+        let polygon: Polygon<Point> = Polygon {
+            points: points.as_slice(),
+            closed: true,
+        };
+
+        match mode {
+            TessellationMode::Fill(options) => {
+                let ref mut output = BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                    let p = vertex.position();
+                    let color = Color::WHITE;
+                    ShapeVertex {
+                        position: [p.x, p.y, transform.translation.z()],
+                        color: [color.r, color.g, color.b, color.a],
+                    }
+                });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_polygon(
+                        polygon,
+                        &options,
+                        output,
+                    )
+                    .unwrap();
+            }
+            TessellationMode::Stroke(options) => {
+                let ref mut output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color = Color::WHITE;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_polygon(
+                        polygon,
+                        &options,
+                        output,
+                    )
+                    .unwrap();
+            }
+            TessellationMode::FillAndStroke {
+                fill,
+                stroke,
+                fill_color,
+                stroke_color,
+            } => {
+                let ref mut fill_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                        let p = vertex.position();
+                        let color = fill_color;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_polygon(
+                        polygon,
+                        &fill,
+                        fill_output,
+                    )
+                    .unwrap();
+
+                let ref mut stroke_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color = stroke_color;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_polygon(
+                        polygon,
+                        &stroke,
+                        stroke_output,
+                    )
+                    .unwrap();
+            }
+        }
+
+        create_sprite(material, meshes, geometry, sprite_translation(&transform))
+    }
+}
+
+/// Builds a [`PathShape`] command-by-command, mirroring lyon's own path
+/// builder and SVG's `<path>` grammar.
+#[derive(Debug, Default)]
+pub struct PathShapeBuilder {
+    builder: Builder,
+}
+
+impl PathShapeBuilder {
+    pub fn new() -> Self {
+        Self {
+            builder: Builder::new(),
+        }
+    }
+
+    pub fn move_to(mut self, to: Vec2) -> Self {
+        self.builder.move_to(to.to_lyon_point());
+        self
+    }
+
+    pub fn line_to(mut self, to: Vec2) -> Self {
+        self.builder.line_to(to.to_lyon_point());
+        self
+    }
+
+    pub fn quadratic_bezier_to(mut self, ctrl: Vec2, to: Vec2) -> Self {
+        self.builder
+            .quadratic_bezier_to(ctrl.to_lyon_point(), to.to_lyon_point());
+        self
+    }
+
+    pub fn cubic_bezier_to(mut self, ctrl1: Vec2, ctrl2: Vec2, to: Vec2) -> Self {
+        self.builder.cubic_bezier_to(
+            ctrl1.to_lyon_point(),
+            ctrl2.to_lyon_point(),
+            to.to_lyon_point(),
+        );
+        self
+    }
+
+    /// Arcs around `center` by `sweep_angle` radians, rotated `x_rotation`
+    /// radians from the x-axis.
+    pub fn arc_to(mut self, center: Vec2, radii: Vec2, sweep_angle: f32, x_rotation: f32) -> Self {
+        self.builder.arc(
+            center.to_lyon_point(),
+            radii.to_lyon_vector(),
+            Angle::radians(sweep_angle),
+            Angle::radians(x_rotation),
+        );
+        self
+    }
+
+    pub fn close(mut self) -> Self {
+        self.builder.close();
+        self
+    }
+
+    pub fn build(self) -> PathShape {
+        PathShape {
+            path: self.builder.build(),
+        }
+    }
+}
+
+/// An arbitrary outline built from straight lines, bezier curves and arcs,
+/// for logos, icons and other freeform shapes that don't fit the primitive
+/// generators above.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathShape {
+    path: Path,
+}
+
+impl PathShape {
+    /// Starts building a path from scratch.
+    pub fn builder() -> PathShapeBuilder {
+        PathShapeBuilder::new()
+    }
+
+    /// Parses a (very small) subset of the SVG path `d` grammar: the
+    /// absolute `M`, `L`, `Q`, `C` and `Z` commands, each followed by its
+    /// comma/whitespace-separated arguments.
+    pub fn from_svg(d: &str) -> Result<Self, String> {
+        // Reads the 2D point starting at `tokens[i]`, erroring instead of
+        // silently truncating if the group is incomplete.
+        fn parse_xy(tokens: &[&str], i: usize) -> Result<Vec2, String> {
+            let x = tokens
+                .get(i)
+                .ok_or_else(|| "expected a coordinate".to_string())?
+                .parse::<f32>()
+                .map_err(|e| e.to_string())?;
+            let y = tokens
+                .get(i + 1)
+                .ok_or_else(|| "expected a coordinate".to_string())?
+                .parse::<f32>()
+                .map_err(|e| e.to_string())?;
+            Ok(Vec2::new(x, y))
+        }
+
+        let mut builder = PathShapeBuilder::new();
+        let mut command = None;
+        let mut segment = String::new();
+
+        // SVG allows repeating a command's coordinate group without
+        // re-stating the command letter (e.g. "L 1 1 2 2" is two linetos),
+        // so each arm loops over every complete group instead of taking
+        // just the first.
+        let mut flush = |command: &mut Option<char>,
+                         segment: &mut String,
+                         builder: &mut PathShapeBuilder|
+         -> Result<(), String> {
+            if let Some(c) = command.take() {
+                let tokens: Vec<&str> = segment
+                    .split(|ch: char| ch == ',' || ch.is_whitespace())
+                    .filter(|s| !s.is_empty())
+                    .collect();
+                let built = std::mem::replace(builder, PathShapeBuilder::new());
+                *builder = match c {
+                    'Z' => {
+                        if !tokens.is_empty() {
+                            return Err("Z takes no arguments".to_string());
+                        }
+                        built.close()
+                    }
+                    'M' => {
+                        let mut b = built.move_to(parse_xy(&tokens, 0)?);
+                        let mut i = 2;
+                        while i < tokens.len() {
+                            b = b.line_to(parse_xy(&tokens, i)?);
+                            i += 2;
+                        }
+                        b
+                    }
+                    'L' => {
+                        let mut b = built;
+                        let mut i = 0;
+                        while i < tokens.len() {
+                            b = b.line_to(parse_xy(&tokens, i)?);
+                            i += 2;
+                        }
+                        b
+                    }
+                    'Q' => {
+                        let mut b = built;
+                        let mut i = 0;
+                        while i < tokens.len() {
+                            let ctrl = parse_xy(&tokens, i)?;
+                            let to = parse_xy(&tokens, i + 2)?;
+                            b = b.quadratic_bezier_to(ctrl, to);
+                            i += 4;
+                        }
+                        b
+                    }
+                    'C' => {
+                        let mut b = built;
+                        let mut i = 0;
+                        while i < tokens.len() {
+                            let ctrl1 = parse_xy(&tokens, i)?;
+                            let ctrl2 = parse_xy(&tokens, i + 2)?;
+                            let to = parse_xy(&tokens, i + 4)?;
+                            b = b.cubic_bezier_to(ctrl1, ctrl2, to);
+                            i += 6;
+                        }
+                        b
+                    }
+                    other => return Err(format!("unsupported SVG path command: {}", other)),
+                };
+            }
+            segment.clear();
+            Ok(())
+        };
+
+        for c in d.chars() {
+            if "MLQCZ".contains(c) {
+                flush(&mut command, &mut segment, &mut builder)?;
+                command = Some(c);
+            } else {
+                segment.push(c);
+            }
+        }
+        flush(&mut command, &mut segment, &mut builder)?;
+
+        Ok(builder.build())
+    }
+}
+
+#[cfg(test)]
+mod path_shape_tests {
+    use super::*;
+
+    #[test]
+    fn repeated_coordinate_groups_become_implicit_linetos() {
+        let explicit = PathShape::from_svg("M 0 0 L 1 1 L 2 2").unwrap();
+        let implicit = PathShape::from_svg("M 0 0 L 1 1 2 2").unwrap();
+
+        assert_eq!(explicit, implicit);
+    }
+
+    #[test]
+    fn repeated_groups_after_move_to_become_implicit_linetos() {
+        let explicit = PathShape::from_svg("M 0 0 L 1 1 L 2 2").unwrap();
+        let implicit = PathShape::from_svg("M 0 0 1 1 2 2").unwrap();
+
+        assert_eq!(explicit, implicit);
+    }
+
+    #[test]
+    fn z_rejects_trailing_arguments() {
+        assert!(PathShape::from_svg("M 0 0 Z 1 1").is_err());
+    }
+
+    #[test]
+    fn incomplete_coordinate_group_is_an_error() {
+        assert!(PathShape::from_svg("M 0 0 L 1").is_err());
+    }
+}
+
+impl ShapeSprite for PathShape {
+    fn generate_sprite(
+        &self,
+        material: Handle<ColorMaterial>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        tessellator: &mut Tessellator,
+        mode: TessellationMode,
+        transform: Transform,
+    ) -> SpriteBundle {
+        let mut geometry = Geometry(VertexBuffers::new());
+
+        match mode {
+            TessellationMode::Fill(options) => {
+                let ref mut output = BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                    let p = vertex.position();
+                    let color = Color::WHITE;
+                    ShapeVertex {
+                        position: [p.x, p.y, transform.translation.z()],
+                        color: [color.r, color.g, color.b, color.a],
+                    }
+                });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_path(
+                        &self.path,
+                        &options,
+                        output,
+                    )
+                    .unwrap();
+            }
+            TessellationMode::Stroke(options) => {
+                let ref mut output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color = Color::WHITE;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_path(
+                        &self.path,
+                        &options,
+                        output,
+                    )
+                    .unwrap();
+            }
+            TessellationMode::FillAndStroke {
+                fill,
+                stroke,
+                fill_color,
+                stroke_color,
+            } => {
+                let ref mut fill_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: FillVertex| {
+                        let p = vertex.position();
+                        let color = fill_color;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .fill
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_path(
+                        &self.path,
+                        &fill,
+                        fill_output,
+                    )
+                    .unwrap();
+
+                let ref mut stroke_output =
+                    BuffersBuilder::new(&mut geometry.0, |vertex: StrokeVertex| {
+                        let p = vertex.position();
+                        let color = stroke_color;
+                        ShapeVertex {
+                            position: [p.x, p.y, transform.translation.z()],
+                            color: [color.r, color.g, color.b, color.a],
+                        }
+                    });
+                tessellator
+                    .stroke
+                    .as_mut()
+                    .unwrap()
+                    .tessellate_path(
+                        &self.path,
+                        &stroke,
+                        stroke_output,
+                    )
                     .unwrap();
             }
         }
 
-        create_sprite(material, meshes, geometry, transform.translation)
+        create_sprite(material, meshes, geometry, sprite_translation(&transform))
     }
 }
@@ -0,0 +1,24 @@
+//! Conversions between Bevy's math types and lyon's.
+
+use bevy::prelude::Vec2;
+use lyon_tessellation::math::{point, vector, Point, Vector};
+
+pub trait ToLyonPoint {
+    fn to_lyon_point(&self) -> Point;
+}
+
+impl ToLyonPoint for Vec2 {
+    fn to_lyon_point(&self) -> Point {
+        point(self.x(), self.y())
+    }
+}
+
+pub trait ToLyonVector {
+    fn to_lyon_vector(&self) -> Vector;
+}
+
+impl ToLyonVector for Vec2 {
+    fn to_lyon_vector(&self) -> Vector {
+        vector(self.x(), self.y())
+    }
+}
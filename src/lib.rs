@@ -0,0 +1,108 @@
+//! Tessellated 2D vector shapes for Bevy, built on top of lyon.
+
+pub mod basic_shapes;
+pub mod conversions;
+mod shape_plugin;
+
+pub use shape_plugin::{ShapeDescriptor, ShapePlugin};
+
+use bevy::{
+    prelude::*,
+    render::{
+        mesh::{Indices, VertexAttributeValues},
+        pipeline::PrimitiveTopology,
+    },
+};
+use lyon_tessellation::{
+    FillOptions, FillTessellator, StrokeOptions, StrokeTessellator, VertexBuffers,
+};
+
+/// A single tessellated vertex: its local x/y position with the shape's
+/// depth (its transform's z) as z, plus a color sampled from a gradient or
+/// a shape's fill/stroke color. Emitted into the mesh as
+/// `ATTRIBUTE_COLOR` so a vertex-color material can render it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShapeVertex {
+    pub position: [f32; 3],
+    pub color: [f32; 4],
+}
+
+/// Accumulates the tessellated vertices and indices for a single shape
+/// before they're uploaded as a [`Mesh`].
+pub struct Geometry(pub VertexBuffers<ShapeVertex, u32>);
+
+/// Whether (and how) a shape should be filled, stroked, or both.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TessellationMode {
+    Fill(FillOptions),
+    Stroke(StrokeOptions),
+    /// Tessellates a fill pass and a stroke pass into the same mesh, so a
+    /// single entity can render a filled shape with a contrasting border.
+    FillAndStroke {
+        fill: FillOptions,
+        stroke: StrokeOptions,
+        fill_color: Color,
+        stroke_color: Color,
+    },
+}
+
+/// Holds the lyon tessellators used to turn shapes into triangle meshes.
+pub struct Tessellator {
+    pub fill: Option<FillTessellator>,
+    pub stroke: Option<StrokeTessellator>,
+}
+
+impl Tessellator {
+    pub fn new() -> Self {
+        Self {
+            fill: Some(FillTessellator::new()),
+            stroke: Some(StrokeTessellator::new()),
+        }
+    }
+}
+
+impl Default for Tessellator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implemented by every shape type that can be tessellated into a
+/// [`SpriteBundle`].
+pub trait ShapeSprite {
+    fn generate_sprite(
+        &self,
+        material: Handle<ColorMaterial>,
+        meshes: &mut ResMut<Assets<Mesh>>,
+        tessellator: &mut Tessellator,
+        mode: TessellationMode,
+        transform: Transform,
+    ) -> SpriteBundle;
+}
+
+/// Uploads tessellated `geometry` as a [`Mesh`] (positions and per-vertex
+/// colors) and wraps it in a [`SpriteBundle`] positioned at `translation`.
+pub(crate) fn create_sprite(
+    material: Handle<ColorMaterial>,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    geometry: Geometry,
+    translation: Vec3,
+) -> SpriteBundle {
+    let positions: Vec<[f32; 3]> = geometry.0.vertices.iter().map(|v| v.position).collect();
+    let colors: Vec<[f32; 4]> = geometry.0.vertices.iter().map(|v| v.color).collect();
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.set_attribute(
+        Mesh::ATTRIBUTE_POSITION,
+        VertexAttributeValues::Float3(positions),
+    );
+    mesh.set_attribute(Mesh::ATTRIBUTE_COLOR, VertexAttributeValues::Float4(colors));
+    mesh.set_indices(Some(Indices::U32(geometry.0.indices)));
+
+    SpriteBundle {
+        mesh: meshes.add(mesh),
+        material,
+        transform: Transform::from_translation(translation),
+        ..Default::default()
+    }
+}
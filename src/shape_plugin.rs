@@ -0,0 +1,22 @@
+//! Plugin scaffolding for queueing shapes to be tessellated into sprites.
+
+use crate::{ShapeSprite, TessellationMode};
+use bevy::prelude::*;
+
+/// Describes a shape that should be tessellated into a [`SpriteBundle`].
+///
+/// Nothing drains queued descriptors yet; shapes are tessellated by calling
+/// [`ShapeSprite::generate_sprite`] directly until that's wired up.
+pub struct ShapeDescriptor {
+    pub shape: Box<dyn ShapeSprite + Send + Sync>,
+    pub material: Handle<ColorMaterial>,
+    pub mode: TessellationMode,
+    pub transform: Transform,
+}
+
+/// Reserves the shape-related app setup; doesn't register any systems yet.
+pub struct ShapePlugin;
+
+impl Plugin for ShapePlugin {
+    fn build(&self, _app: &mut AppBuilder) {}
+}